@@ -72,11 +72,41 @@ impl Write for Formatter {
 
 pub(crate) type FormatFn = Box<dyn Fn(&mut Formatter, &Record) -> io::Result<()> + Sync + Send>;
 
+#[cfg(feature = "kv")]
+pub(crate) type KeyValueFormatFn =
+    Box<dyn Fn(&mut Formatter, &dyn log::kv::Source) -> io::Result<()> + Sync + Send>;
+
+/// The precision with which a timestamp is rendered in the log header.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum TimestampPrecision {
+    /// Full seconds, e.g. `2020-01-01T00:00:00Z`.
+    Seconds,
+    /// Milliseconds, e.g. `2020-01-01T00:00:00.000Z`.
+    Millis,
+    /// Microseconds, e.g. `2020-01-01T00:00:00.000000Z`.
+    Micros,
+    /// Nanoseconds, e.g. `2020-01-01T00:00:00.000000000Z`.
+    Nanos,
+}
+
+/// The default timestamp precision is seconds.
+impl Default for TimestampPrecision {
+    fn default() -> Self {
+        TimestampPrecision::Seconds
+    }
+}
+
 pub(crate) struct Builder {
     pub format_module_path: bool,
     pub format_target: bool,
     pub format_level: bool,
+    pub format_timestamp: Option<TimestampPrecision>,
+    #[cfg(feature = "kv")]
+    pub format_key_values: bool,
+    #[cfg(feature = "kv")]
+    pub kv_format: Option<KeyValueFormatFn>,
     pub format_suffix: &'static str,
+    pub custom_format: Option<FormatFn>,
     built: bool,
 }
 
@@ -92,12 +122,27 @@ impl Builder {
             },
         );
 
+        if let Some(format) = built.custom_format {
+            return format;
+        }
+
+        // Parse the timestamp format description once, here, rather than on every
+        // logged record in `write_timestamp`.
+        #[cfg(feature = "time")]
+        let timestamp_format = built.format_timestamp.map(timestamp_format_description);
+
         Box::new(move |buf, record| {
             let fmt = DefaultFormat {
+                #[cfg(feature = "time")]
+                timestamp_format: timestamp_format.as_deref(),
                 module_path: built.format_module_path,
                 target: built.format_target,
                 level: built.format_level,
                 written_header_value: false,
+                #[cfg(feature = "kv")]
+                key_values: built.format_key_values,
+                #[cfg(feature = "kv")]
+                kv_format: built.kv_format.as_ref(),
                 suffix: built.format_suffix,
                 buf,
             };
@@ -113,7 +158,13 @@ impl Default for Builder {
             format_module_path: false,
             format_target: true,
             format_level: true,
+            format_timestamp: Some(Default::default()),
+            #[cfg(feature = "kv")]
+            format_key_values: true,
+            #[cfg(feature = "kv")]
+            kv_format: None,
             format_suffix: "\n",
+            custom_format: None,
             built: false,
         }
     }
@@ -139,32 +190,36 @@ impl<T: std::fmt::Display> std::fmt::Display for StyledValue<T> {
 }
 
 struct DefaultFormat<'a> {
+    #[cfg(feature = "time")]
+    timestamp_format: Option<&'a [time::format_description::FormatItem<'static>]>,
     module_path: bool,
     target: bool,
     level: bool,
     written_header_value: bool,
+    #[cfg(feature = "kv")]
+    key_values: bool,
+    #[cfg(feature = "kv")]
+    kv_format: Option<&'a KeyValueFormatFn>,
     buf: &'a mut Formatter,
     suffix: &'a str,
 }
 
 impl<'a> DefaultFormat<'a> {
     fn write(mut self, record: &Record) -> io::Result<()> {
+        self.write_timestamp()?;
         self.write_level(record)?;
         self.write_module_path(record)?;
         self.write_target(record)?;
         self.finish_header()?;
 
         self.write_args(record)?;
+        self.write_key_values(record)?;
         write!(self.buf, "{}", self.suffix)
     }
 
     fn subtle_style(&self, text: &'static str) -> SubtleStyle {
         StyledValue {
-            style: if self.buf.write_style == anstream::ColorChoice::Never {
-                style::Style::new()
-            } else {
-                style::AnsiColor::BrightBlack.on_default()
-            },
+            style: subtle_style(self.buf),
             value: text,
         }
     }
@@ -199,6 +254,26 @@ impl<'a> DefaultFormat<'a> {
         self.write_header_value(format_args!("{:<5}", level))
     }
 
+    #[cfg(feature = "time")]
+    fn write_timestamp(&mut self) -> io::Result<()> {
+        let format = match self.timestamp_format {
+            Some(format) => format,
+            None => return Ok(()),
+        };
+
+        let now = time::OffsetDateTime::now_utc();
+        let ts = now
+            .format(format)
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+
+        self.write_header_value(ts)
+    }
+
+    #[cfg(not(feature = "time"))]
+    fn write_timestamp(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
     fn write_module_path(&mut self, record: &Record) -> io::Result<()> {
         if !self.module_path {
             return Ok(());
@@ -234,4 +309,361 @@ impl<'a> DefaultFormat<'a> {
     fn write_args(&mut self, record: &Record) -> io::Result<()> {
         write!(self.buf, "{}", record.args())
     }
+
+    #[cfg(feature = "kv")]
+    fn write_key_values(&mut self, record: &Record) -> io::Result<()> {
+        if !self.key_values {
+            return Ok(());
+        }
+
+        if let Some(kv_format) = self.kv_format {
+            return kv_format(self.buf, record.key_values());
+        }
+
+        let mut visitor = KeyValueVisitor { buf: self.buf };
+        record
+            .key_values()
+            .visit(&mut visitor)
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))
+    }
+
+    #[cfg(not(feature = "kv"))]
+    fn write_key_values(&mut self, _record: &Record) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Appends each structured field as a ` key=value` pair, with the key styled
+/// subtly to match the header.
+#[cfg(feature = "kv")]
+struct KeyValueVisitor<'a> {
+    buf: &'a mut Formatter,
+}
+
+#[cfg(feature = "kv")]
+impl<'kvs> log::kv::VisitSource<'kvs> for KeyValueVisitor<'_> {
+    fn visit_pair(
+        &mut self,
+        key: log::kv::Key<'kvs>,
+        value: log::kv::Value<'kvs>,
+    ) -> Result<(), log::kv::Error> {
+        let key = StyledValue {
+            style: subtle_style(self.buf),
+            value: key,
+        };
+
+        write!(self.buf, " {}={}", key, value).map_err(log::kv::Error::boxed)?;
+        Ok(())
+    }
+}
+
+/// Builds the `time` format description used to render a UTC, RFC 3339
+/// timestamp at the requested sub-second precision.
+#[cfg(feature = "time")]
+fn timestamp_format_description(
+    precision: TimestampPrecision,
+) -> Vec<time::format_description::FormatItem<'static>> {
+    use time::format_description;
+
+    let description = match precision {
+        TimestampPrecision::Seconds => "[year]-[month]-[day]T[hour]:[minute]:[second]Z",
+        TimestampPrecision::Millis => {
+            "[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond digits:3]Z"
+        }
+        TimestampPrecision::Micros => {
+            "[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond digits:6]Z"
+        }
+        TimestampPrecision::Nanos => {
+            "[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond digits:9]Z"
+        }
+    };
+
+    format_description::parse(description).expect("static timestamp format description is valid")
+}
+
+/// A single ordered component of a log line assembled by [`FormatBuilder`].
+///
+/// Each piece is rendered in the order it was added, with no implicit
+/// separators or brackets (apart from the space [`FormatPiece::KeyValues`]
+/// writes before each pair), so the caller is responsible for spacing via
+/// [`FormatPiece::Literal`].
+#[non_exhaustive]
+pub enum FormatPiece {
+    /// The record timestamp at the given precision. Added by
+    /// [`FormatBuilder::timestamp`], which is only available with the `time`
+    /// feature enabled.
+    Timestamp(TimestampPrecision),
+    /// The record level, styled with the default level color.
+    Level,
+    /// The module path the record originated from, if any.
+    ModulePath,
+    /// The record target, if non-empty.
+    Target,
+    /// The record's structured key-value pairs, each written as ` key=value`.
+    /// Added by [`FormatBuilder::key_values`], which is only available with the
+    /// `kv` feature enabled.
+    KeyValues,
+    /// A fixed string written verbatim, styled subtly (useful for separators
+    /// and brackets).
+    Literal(&'static str),
+    /// The log message, i.e. `record.args()`.
+    Args,
+    /// A single newline.
+    NewLine,
+}
+
+/// Declaratively assembles a [`FormatFn`] from ordered [`FormatPiece`]s.
+///
+/// Unlike the fixed [`DefaultFormat`] header ordering, this lets callers put
+/// fields and separators wherever they like without hand-writing a closure:
+///
+/// ```ignore
+/// let format = FormatBuilder::new()
+///     .timestamp()
+///     .literal(" [")
+///     .level()
+///     .literal("] ")
+///     .target()
+///     .args()
+///     .new_line()
+///     .build();
+/// builder.format(format);
+/// ```
+#[derive(Default)]
+pub struct FormatBuilder {
+    pieces: Vec<FormatPiece>,
+}
+
+impl FormatBuilder {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Appends a UTC, RFC 3339 timestamp at seconds precision.
+    #[cfg(feature = "time")]
+    pub fn timestamp(&mut self) -> &mut Self {
+        self.timestamp_precision(Default::default())
+    }
+
+    /// Appends a UTC, RFC 3339 timestamp at the given sub-second precision.
+    #[cfg(feature = "time")]
+    pub fn timestamp_precision(&mut self, precision: TimestampPrecision) -> &mut Self {
+        self.pieces.push(FormatPiece::Timestamp(precision));
+        self
+    }
+
+    pub fn level(&mut self) -> &mut Self {
+        self.pieces.push(FormatPiece::Level);
+        self
+    }
+
+    pub fn module_path(&mut self) -> &mut Self {
+        self.pieces.push(FormatPiece::ModulePath);
+        self
+    }
+
+    pub fn target(&mut self) -> &mut Self {
+        self.pieces.push(FormatPiece::Target);
+        self
+    }
+
+    #[cfg(feature = "kv")]
+    pub fn key_values(&mut self) -> &mut Self {
+        self.pieces.push(FormatPiece::KeyValues);
+        self
+    }
+
+    pub fn literal(&mut self, text: &'static str) -> &mut Self {
+        self.pieces.push(FormatPiece::Literal(text));
+        self
+    }
+
+    pub fn args(&mut self) -> &mut Self {
+        self.pieces.push(FormatPiece::Args);
+        self
+    }
+
+    pub fn new_line(&mut self) -> &mut Self {
+        self.pieces.push(FormatPiece::NewLine);
+        self
+    }
+
+    pub fn build(&mut self) -> FormatFn {
+        // Compile the pieces once here — notably parsing each timestamp's format
+        // description — so the closure doesn't redo that work per record.
+        let pieces: Vec<CompiledPiece> =
+            mem::take(&mut self.pieces).into_iter().map(Into::into).collect();
+
+        Box::new(move |buf, record| {
+            for piece in &pieces {
+                write_piece(buf, record, piece)?;
+            }
+            Ok(())
+        })
+    }
+}
+
+/// A [`FormatPiece`] with any per-build work done ahead of time, so rendering a
+/// record doesn't repeat it. Currently that is the parsed timestamp format
+/// description, which would otherwise be re-parsed on every line.
+enum CompiledPiece {
+    #[cfg(feature = "time")]
+    Timestamp(Vec<time::format_description::FormatItem<'static>>),
+    #[cfg(not(feature = "time"))]
+    Timestamp,
+    Level,
+    ModulePath,
+    Target,
+    KeyValues,
+    Literal(&'static str),
+    Args,
+    NewLine,
+}
+
+impl From<FormatPiece> for CompiledPiece {
+    fn from(piece: FormatPiece) -> Self {
+        match piece {
+            FormatPiece::Timestamp(_precision) => {
+                #[cfg(feature = "time")]
+                {
+                    CompiledPiece::Timestamp(timestamp_format_description(_precision))
+                }
+                #[cfg(not(feature = "time"))]
+                {
+                    CompiledPiece::Timestamp
+                }
+            }
+            FormatPiece::Level => CompiledPiece::Level,
+            FormatPiece::ModulePath => CompiledPiece::ModulePath,
+            FormatPiece::Target => CompiledPiece::Target,
+            FormatPiece::KeyValues => CompiledPiece::KeyValues,
+            FormatPiece::Literal(text) => CompiledPiece::Literal(text),
+            FormatPiece::Args => CompiledPiece::Args,
+            FormatPiece::NewLine => CompiledPiece::NewLine,
+        }
+    }
+}
+
+/// Serializes a record as a single-line JSON object for machine consumption.
+///
+/// The output never contains ANSI escapes regardless of the detected write
+/// style, since the fields are written via `serde_json` rather than the
+/// styled header helpers.
+#[cfg(feature = "json")]
+pub(crate) fn write_json(buf: &mut Formatter, record: &Record) -> io::Result<()> {
+    let mut fields = serde_json::Map::new();
+    #[cfg(feature = "kv")]
+    {
+        let mut visitor = JsonKeyValueVisitor {
+            fields: &mut fields,
+        };
+        record
+            .key_values()
+            .visit(&mut visitor)
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+    }
+
+    let timestamp: Option<String> = {
+        #[cfg(feature = "time")]
+        {
+            time::OffsetDateTime::now_utc()
+                .format(&time::format_description::well_known::Rfc3339)
+                .ok()
+        }
+        #[cfg(not(feature = "time"))]
+        {
+            None
+        }
+    };
+
+    let object = serde_json::json!({
+        "level": record.level().as_str(),
+        "target": record.target(),
+        "module": record.module_path(),
+        "timestamp": timestamp,
+        "message": record.args().to_string(),
+        "fields": serde_json::Value::Object(fields),
+    });
+
+    writeln!(buf, "{}", object)
+}
+
+/// Collects structured fields into a JSON object, stringifying each value.
+#[cfg(all(feature = "json", feature = "kv"))]
+struct JsonKeyValueVisitor<'a> {
+    fields: &'a mut serde_json::Map<String, serde_json::Value>,
+}
+
+#[cfg(all(feature = "json", feature = "kv"))]
+impl<'kvs> log::kv::VisitSource<'kvs> for JsonKeyValueVisitor<'_> {
+    fn visit_pair(
+        &mut self,
+        key: log::kv::Key<'kvs>,
+        value: log::kv::Value<'kvs>,
+    ) -> Result<(), log::kv::Error> {
+        self.fields.insert(
+            key.to_string(),
+            serde_json::Value::String(value.to_string()),
+        );
+        Ok(())
+    }
+}
+
+fn subtle_style(buf: &Formatter) -> style::Style {
+    if buf.write_style == anstream::ColorChoice::Never {
+        style::Style::new()
+    } else {
+        style::AnsiColor::BrightBlack.on_default()
+    }
+}
+
+fn write_piece(buf: &mut Formatter, record: &Record, piece: &CompiledPiece) -> io::Result<()> {
+    match piece {
+        CompiledPiece::Level => {
+            let level = record.level();
+            let level = StyledValue {
+                style: buf.default_level_style(level),
+                value: level,
+            };
+            write!(buf, "{}", level)
+        }
+        CompiledPiece::ModulePath => match record.module_path() {
+            Some(module_path) => write!(buf, "{}", module_path),
+            None => Ok(()),
+        },
+        CompiledPiece::Target => match record.target() {
+            "" => Ok(()),
+            target => write!(buf, "{}", target),
+        },
+        CompiledPiece::Literal(text) => {
+            let literal = StyledValue {
+                style: subtle_style(buf),
+                value: *text,
+            };
+            write!(buf, "{}", literal)
+        }
+        CompiledPiece::Args => write!(buf, "{}", record.args()),
+        CompiledPiece::NewLine => writeln!(buf),
+        #[cfg(feature = "time")]
+        CompiledPiece::Timestamp(format) => {
+            let now = time::OffsetDateTime::now_utc();
+            let ts = now
+                .format(format)
+                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+            write!(buf, "{}", ts)
+        }
+        #[cfg(not(feature = "time"))]
+        CompiledPiece::Timestamp => Ok(()),
+        CompiledPiece::KeyValues => {
+            #[cfg(feature = "kv")]
+            {
+                let mut visitor = KeyValueVisitor { buf };
+                record
+                    .key_values()
+                    .visit(&mut visitor)
+                    .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+            }
+            Ok(())
+        }
+    }
 }
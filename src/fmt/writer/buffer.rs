@@ -2,16 +2,25 @@ use std::{io, sync::Mutex};
 
 #[derive(Debug)]
 pub(in crate::fmt::writer) struct BufferWriter {
+    sinks: Vec<Sink>,
+    // The color choice used to render the shared buffer. Individual sinks strip
+    // ANSI as needed, so this is the most permissive choice across the sinks.
+    buffer_write_style: anstream::ColorChoice,
+}
+
+/// A single destination the buffer is written to, with its own color choice.
+#[derive(Debug)]
+pub(in crate::fmt::writer) struct Sink {
     target: WritableTarget,
     write_style: anstream::ColorChoice,
 }
 
-impl BufferWriter {
+impl Sink {
     pub(in crate::fmt::writer) fn stderr(
         is_test: bool,
         write_style: anstream::ColorChoice,
     ) -> Self {
-        BufferWriter {
+        Sink {
             target: if is_test {
                 WritableTarget::PrintStderr
             } else {
@@ -25,7 +34,7 @@ impl BufferWriter {
         is_test: bool,
         write_style: anstream::ColorChoice,
     ) -> Self {
-        BufferWriter {
+        Sink {
             target: if is_test {
                 WritableTarget::PrintStdout
             } else {
@@ -39,24 +48,15 @@ impl BufferWriter {
         pipe: Box<Mutex<dyn io::Write + Send + 'static>>,
         write_style: anstream::ColorChoice,
     ) -> Self {
-        BufferWriter {
+        Sink {
             target: WritableTarget::Pipe(pipe),
             write_style,
         }
     }
 
-    pub(in crate::fmt::writer) fn write_style(&self) -> anstream::ColorChoice {
-        self.write_style
-    }
-
-    pub(in crate::fmt::writer) fn buffer(&self) -> Buffer {
-        Buffer(Vec::new())
-    }
-
-    pub(in crate::fmt::writer) fn print(&self, buf: &Buffer) -> io::Result<()> {
+    fn print(&self, buf: &[u8]) -> io::Result<()> {
         use std::io::Write as _;
 
-        let buf = buf.as_bytes();
         match &self.target {
             WritableTarget::WriteStdout => {
                 let stream = std::io::stdout();
@@ -67,7 +67,11 @@ impl BufferWriter {
             }
             WritableTarget::PrintStdout => {
                 let buf = String::from_utf8_lossy(buf);
-                print!("{}", buf);
+                if self.write_style == anstream::ColorChoice::Never {
+                    print!("{}", anstream::adapter::strip_str(&buf));
+                } else {
+                    print!("{}", buf);
+                }
             }
             WritableTarget::WriteStderr => {
                 let stream = std::io::stderr();
@@ -78,12 +82,22 @@ impl BufferWriter {
             }
             WritableTarget::PrintStderr => {
                 let buf = String::from_utf8_lossy(buf);
-                eprint!("{}", buf);
+                if self.write_style == anstream::ColorChoice::Never {
+                    eprint!("{}", anstream::adapter::strip_str(&buf));
+                } else {
+                    eprint!("{}", buf);
+                }
             }
             WritableTarget::Pipe(pipe) => {
                 let mut stream = pipe.lock().unwrap();
-                stream.write_all(buf)?;
-                stream.flush()?;
+                if self.write_style == anstream::ColorChoice::Never {
+                    let mut stream = anstream::StripStream::new(&mut *stream);
+                    stream.write_all(buf)?;
+                    stream.flush()?;
+                } else {
+                    stream.write_all(buf)?;
+                    stream.flush()?;
+                }
             }
         }
 
@@ -91,6 +105,40 @@ impl BufferWriter {
     }
 }
 
+impl BufferWriter {
+    pub(in crate::fmt::writer) fn new(sinks: Vec<Sink>) -> Self {
+        // Style the shared buffer if any sink wants color; `Never` sinks strip
+        // the ANSI back out when printing (see `Sink::print`).
+        let buffer_write_style = sinks
+            .iter()
+            .map(|sink| sink.write_style)
+            .find(|choice| *choice != anstream::ColorChoice::Never)
+            .unwrap_or(anstream::ColorChoice::Never);
+
+        BufferWriter {
+            sinks,
+            buffer_write_style,
+        }
+    }
+
+    pub(in crate::fmt::writer) fn write_style(&self) -> anstream::ColorChoice {
+        self.buffer_write_style
+    }
+
+    pub(in crate::fmt::writer) fn buffer(&self) -> Buffer {
+        Buffer(Vec::new())
+    }
+
+    pub(in crate::fmt::writer) fn print(&self, buf: &Buffer) -> io::Result<()> {
+        let buf = buf.as_bytes();
+        for sink in &self.sinks {
+            sink.print(buf)?;
+        }
+
+        Ok(())
+    }
+}
+
 pub(in crate::fmt) struct Buffer(Vec<u8>);
 impl Buffer {
     pub(in crate::fmt) fn clear(&mut self) {
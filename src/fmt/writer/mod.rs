@@ -2,7 +2,7 @@ mod buffer;
 mod target;
 
 pub(super) use buffer::Buffer;
-use buffer::BufferWriter;
+use buffer::{BufferWriter, Sink};
 use std::{io, mem, sync::Mutex};
 pub use target::Target;
 
@@ -47,23 +47,54 @@ impl Builder {
         self
     }
 
+    pub(crate) fn target(&mut self, target: Target) -> &mut Self {
+        self.target = target;
+        self
+    }
+
     pub(crate) fn build(&mut self) -> Writer {
         assert!(!self.built, "attempt to re-use consumed builder");
         self.built = true;
 
-        let color_choice = match &self.target {
-            Target::Stdout => anstream::AutoStream::choice(&std::io::stdout()).into(),
-            Target::Stderr => anstream::AutoStream::choice(&std::io::stderr()).into(),
-            Target::Pipe(_) => anstream::ColorChoice::Never,
-        };
+        let mut sinks = Vec::new();
+        collect_sinks(mem::take(&mut self.target), self.is_test, &mut sinks);
 
-        let writer = match mem::take(&mut self.target) {
-            Target::Stdout => BufferWriter::stdout(self.is_test, color_choice),
-            Target::Stderr => BufferWriter::stderr(self.is_test, color_choice),
-            Target::Pipe(pipe) => BufferWriter::pipe(Box::new(Mutex::new(pipe)), color_choice),
-        };
+        if sinks.is_empty() {
+            // An empty `Target::Multi` would otherwise leave `BufferWriter` with
+            // no sinks and silently drop every record; fall back to the default
+            // target so logs still go somewhere.
+            collect_sinks(Target::default(), self.is_test, &mut sinks);
+        }
+
+        Writer {
+            inner: BufferWriter::new(sinks),
+        }
+    }
+}
 
-        Writer { inner: writer }
+/// Flattens a [`Target`] (recursively, for [`Target::Multi`]) into the set of
+/// sinks the buffer should be written to, computing a per-sink color choice.
+fn collect_sinks(target: Target, is_test: bool, sinks: &mut Vec<Sink>) {
+    match target {
+        Target::Stdout => {
+            let color_choice = anstream::AutoStream::choice(&std::io::stdout()).into();
+            sinks.push(Sink::stdout(is_test, color_choice));
+        }
+        Target::Stderr => {
+            let color_choice = anstream::AutoStream::choice(&std::io::stderr()).into();
+            sinks.push(Sink::stderr(is_test, color_choice));
+        }
+        Target::Pipe(pipe) => {
+            sinks.push(Sink::pipe(
+                Box::new(Mutex::new(pipe)),
+                anstream::ColorChoice::Never,
+            ));
+        }
+        Target::Multi(targets) => {
+            for target in targets {
+                collect_sinks(target, is_test, sinks);
+            }
+        }
     }
 }
 
@@ -0,0 +1,35 @@
+use std::io;
+
+/// Log target, either `stdout`, `stderr`, a custom pipe, or several at once.
+#[non_exhaustive]
+pub enum Target {
+    /// Logs will be sent to standard output.
+    Stdout,
+    /// Logs will be sent to standard error.
+    Stderr,
+    /// Logs will be sent to a custom pipe.
+    Pipe(Box<dyn io::Write + Send + 'static>),
+    /// Logs will be fanned out to each of the given targets.
+    Multi(Vec<Target>),
+}
+
+impl Default for Target {
+    fn default() -> Self {
+        Target::Stderr
+    }
+}
+
+impl std::fmt::Debug for Target {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Stdout => "stdout",
+                Self::Stderr => "stderr",
+                Self::Pipe(_) => "pipe",
+                Self::Multi(_) => "multi",
+            }
+        )
+    }
+}
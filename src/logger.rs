@@ -1,4 +1,5 @@
 use std::cell::RefCell;
+use std::io;
 
 use log::{LevelFilter, Log, Metadata, Record, SetLoggerError};
 
@@ -34,12 +35,83 @@ impl Builder {
         self
     }
 
+    /// Configures whether a timestamp is written, and at what precision.
+    ///
+    /// Passing `None` disables the timestamp header. Requires the `time`
+    /// feature to actually render anything.
+    pub fn format_timestamp(&mut self, timestamp: Option<fmt::TimestampPrecision>) -> &mut Self {
+        self.format.format_timestamp = timestamp;
+        self
+    }
+
+    /// Configures the timestamp to be written with second precision.
+    pub fn format_timestamp_secs(&mut self) -> &mut Self {
+        self.format_timestamp(Some(fmt::TimestampPrecision::Seconds))
+    }
+
+    /// Configures the timestamp to be written with millisecond precision.
+    pub fn format_timestamp_millis(&mut self) -> &mut Self {
+        self.format_timestamp(Some(fmt::TimestampPrecision::Millis))
+    }
+
+    /// Configures the timestamp to be written with microsecond precision.
+    pub fn format_timestamp_micros(&mut self) -> &mut Self {
+        self.format_timestamp(Some(fmt::TimestampPrecision::Micros))
+    }
+
+    /// Configures the timestamp to be written with nanosecond precision.
+    pub fn format_timestamp_nanos(&mut self) -> &mut Self {
+        self.format_timestamp(Some(fmt::TimestampPrecision::Nanos))
+    }
+
+    /// Sets a custom closure for rendering a record's structured key-value
+    /// pairs, replacing the default ` key=value` rendering.
+    #[cfg(feature = "kv")]
+    pub fn format_key_values<F>(&mut self, format: F) -> &mut Self
+    where
+        F: Fn(&mut Formatter, &dyn log::kv::Source) -> io::Result<()> + Sync + Send + 'static,
+    {
+        self.format.kv_format = Some(Box::new(format));
+        self
+    }
+
+    /// Serializes every record as a single-line JSON object instead of the
+    /// human-readable default format.
+    ///
+    /// Each line is an object with the keys `level`, `target`, `module`,
+    /// `timestamp`, `message` and `fields`; the order of the keys follows
+    /// `serde_json`'s map ordering.
+    /// The output is always plain (no ANSI), making it suitable for ingestion
+    /// by log collectors.
+    #[cfg(feature = "json")]
+    pub fn format_json(&mut self) -> &mut Self {
+        self.format.custom_format = Some(Box::new(fmt::write_json));
+        self
+    }
+
     /// Configures the end of line suffix.
     pub fn format_suffix(&mut self, suffix: &'static str) -> &mut Self {
         self.format.format_suffix = suffix;
         self
     }
 
+    /// Sets a custom format closure that fully replaces the built-in format.
+    ///
+    /// The closure is called for every record and is handed the [`Formatter`]
+    /// buffer to write into. Providing a format this way overrides
+    /// [`format_level`], [`format_target`] and the other header toggles, since
+    /// the default header is no longer emitted.
+    ///
+    /// [`format_level`]: Self::format_level
+    /// [`format_target`]: Self::format_target
+    pub fn format<F>(&mut self, format: F) -> &mut Self
+    where
+        F: Fn(&mut Formatter, &Record) -> io::Result<()> + Sync + Send + 'static,
+    {
+        self.format.custom_format = Some(Box::new(format));
+        self
+    }
+
     pub fn filter_module(&mut self, module: &str, level: LevelFilter) -> &mut Self {
         self.filter.filter_module(module, level);
         self
@@ -65,6 +137,29 @@ impl Builder {
         self
     }
 
+    /// Sets the target logs are written to.
+    pub fn target(&mut self, target: fmt::Target) -> &mut Self {
+        self.writer.target(target);
+        self
+    }
+
+    /// Fans logs out to several targets at once, e.g. colored stderr plus a
+    /// plain file pipe. Each sink computes its own color choice, so ANSI is
+    /// stripped for the ones that aren't a terminal.
+    ///
+    /// An empty iterator falls back to the default target ([`stderr`]) rather
+    /// than dropping every record.
+    ///
+    /// [`stderr`]: fmt::Target::Stderr
+    pub fn target_tee<I>(&mut self, targets: I) -> &mut Self
+    where
+        I: IntoIterator<Item = fmt::Target>,
+    {
+        self.writer
+            .target(fmt::Target::Multi(targets.into_iter().collect()));
+        self
+    }
+
     pub fn try_init(&mut self) -> Result<(), SetLoggerError> {
         let logger = self.build();
 